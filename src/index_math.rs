@@ -0,0 +1,30 @@
+//! Shared index arithmetic for circular buffers.
+//!
+//! Both the heap-allocated [`RingBuffer`](crate::RingBuffer) and the inline
+//! [`ArrayRingBuffer`](crate::ArrayRingBuffer) keep the same `head`/`len`/`capacity`
+//! bookkeeping and need the same translation from a logical index to a physical offset; this
+//! module is the one place that arithmetic lives.
+
+/// Translate a logical index (0-based from the front of the buffer) into a physical offset into
+/// the backing storage.
+pub(crate) fn physical(head: usize, capacity: usize, logical: usize) -> usize {
+    (head + logical) % capacity
+}
+
+/// Return true if the occupied region does not wrap past the end of the backing storage.
+pub(crate) fn is_contiguous(head: usize, len: usize, capacity: usize) -> bool {
+    head + len <= capacity
+}
+
+/// Split `len` occupied elements starting at `head` into the lengths of the two physical
+/// segments returned by `as_slices`/`as_mut_slices`: the first runs from `head` towards the end
+/// of the backing storage (covering everything if contiguous), the second is whatever wrapped
+/// back around to the start.
+pub(crate) fn segment_lens(head: usize, len: usize, capacity: usize) -> (usize, usize) {
+    if is_contiguous(head, len, capacity) {
+        (len, 0)
+    } else {
+        let first = capacity - head;
+        (first, len - first)
+    }
+}
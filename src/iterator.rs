@@ -1,5 +1,6 @@
 //! Iterators for the ring buffer.
 use crate::RingBuffer;
+use core::ops::{Bound, RangeBounds};
 
 /// An iterator over borrowed elements of a ring buffer.
 pub struct Iter<'a, T> {
@@ -39,6 +40,63 @@ impl<'a, T> ExactSizeIterator for Iter<'a, T> {
     }
 }
 
+/// An iterator over mutably borrowed elements of a ring buffer, in logical front-to-back order.
+///
+/// Built from the two segments returned by [`RingBuffer::as_mut_slices`] rather than repeated
+/// indexing, since the borrow checker can't see that successive `get_mut` calls touch disjoint
+/// elements.
+pub struct IterMut<'a, T> {
+    first: &'a mut [T],
+    second: &'a mut [T],
+}
+
+impl<'a, T> IterMut<'a, T> {
+    pub(crate) fn new(first: &'a mut [T], second: &'a mut [T]) -> Self {
+        IterMut { first, second }
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some((item, rest)) = core::mem::take(&mut self.first).split_first_mut() {
+            self.first = rest;
+            return Some(item);
+        }
+        if let Some((item, rest)) = core::mem::take(&mut self.second).split_first_mut() {
+            self.second = rest;
+            return Some(item);
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.first.len() + self.second.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if let Some((item, rest)) = core::mem::take(&mut self.second).split_last_mut() {
+            self.second = rest;
+            return Some(item);
+        }
+        if let Some((item, rest)) = core::mem::take(&mut self.first).split_last_mut() {
+            self.first = rest;
+            return Some(item);
+        }
+        None
+    }
+}
+
+impl<'a, T> ExactSizeIterator for IterMut<'a, T> {
+    fn len(&self) -> usize {
+        self.first.len() + self.second.len()
+    }
+}
+
 /// An iterator that moves out of a ring buffer.
 ///
 /// Since the buffer is naturally double ended we don't need any special logic to support
@@ -78,10 +136,131 @@ impl<T> DoubleEndedIterator for IntoIter<T> {
     }
 }
 
+/// A draining iterator over a contiguous logical range of a ring buffer, created by
+/// [`RingBuffer::drain`].
+///
+/// Removed elements are yielded by value as the iterator is driven. Dropping the `Drain`
+/// (whether or not it was driven to completion) shifts the remaining tail elements to close the
+/// gap and fixes up `head`/`len`. `len` is reduced to the start of the drained range as soon as
+/// the `Drain` is created, so forgetting it (e.g. via `mem::forget`) simply leaks the
+/// not-yet-relocated tail rather than leaving the buffer in an invalid state.
+pub struct Drain<'a, T> {
+    rb: &'a mut RingBuffer<T>,
+    head: usize,
+    capacity: usize,
+    drain_start: usize,
+    drain_end: usize,
+    original_len: usize,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, T> Drain<'a, T> {
+    pub(crate) fn new(rb: &'a mut RingBuffer<T>, range: impl RangeBounds<usize>) -> Self {
+        let original_len = rb.len;
+        let start = match range.start_bound() {
+            Bound::Included(&n) => n,
+            Bound::Excluded(&n) => n + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&n) => n + 1,
+            Bound::Excluded(&n) => n,
+            Bound::Unbounded => original_len,
+        };
+        assert!(
+            start <= end && end <= original_len,
+            "Drain range out of bounds."
+        );
+
+        let head = rb.head;
+        let capacity = rb.capacity;
+        // Truncate up front: see the leak-safety note on `Drain` above.
+        rb.len = start;
+
+        Drain {
+            rb,
+            head,
+            capacity,
+            drain_start: start,
+            drain_end: end,
+            original_len,
+            front: start,
+            back: end,
+        }
+    }
+
+    fn phys(&self, logical: usize) -> usize {
+        (self.head + logical) % self.capacity
+    }
+}
+
+impl<'a, T> Iterator for Drain<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        let idx = self.phys(self.front);
+        self.front += 1;
+        Some(unsafe { self.rb.ptr.as_ptr().add(idx).read() })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Drain<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front >= self.back {
+            return None;
+        }
+        self.back -= 1;
+        let idx = self.phys(self.back);
+        Some(unsafe { self.rb.ptr.as_ptr().add(idx).read() })
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Drain<'a, T> {
+    fn len(&self) -> usize {
+        self.back - self.front
+    }
+}
+
+impl<'a, T> Drop for Drain<'a, T> {
+    fn drop(&mut self) {
+        // Drop any elements in the range that were never yielded.
+        for i in self.front..self.back {
+            let idx = self.phys(i);
+            unsafe {
+                core::ptr::drop_in_place(self.rb.ptr.as_ptr().add(idx));
+            }
+        }
+
+        // Shift the tail (elements after the drained range) down to close the gap, then restore
+        // `len` to cover the untouched front plus the relocated tail.
+        let tail_len = self.original_len - self.drain_end;
+        for i in 0..tail_len {
+            let src = self.phys(self.drain_end + i);
+            let dst = self.phys(self.drain_start + i);
+            unsafe {
+                let value = self.rb.ptr.as_ptr().add(src).read();
+                self.rb.ptr.as_ptr().add(dst).write(value);
+            }
+        }
+        self.rb.len = self.drain_start + tail_len;
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
     use crate::RingBuffer;
+    #[cfg(feature = "no_std")]
+    use crate::alloc_crate::vec::Vec;
 
     #[test]
     fn test_iter() {
@@ -114,4 +293,74 @@ mod tests {
         let values: Vec<i32> = rb.into_iter().collect();
         assert_eq!(values, [1, 2, 3, 4, 5, 6]);
     }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut rb = RingBuffer::<i32>::with_capacity(6);
+
+        // [5, 6, ., 1, 2, 3, 4]
+        rb.push_back(5);
+        rb.push_back(6);
+        rb.push_front(4);
+        rb.push_front(3);
+        rb.push_front(2);
+        rb.push_front(1);
+
+        for item in rb.iter_mut() {
+            *item *= 10;
+        }
+        let values: Vec<i32> = rb.iter().copied().collect();
+        assert_eq!(values, [10, 20, 30, 40, 50, 60]);
+
+        assert_eq!(rb.iter_mut().next_back(), Some(&mut 60));
+        assert_eq!(rb.iter_mut().len(), 6);
+    }
+
+    #[test]
+    fn test_drain() {
+        let mut rb = RingBuffer::<i32>::with_capacity(6);
+
+        // [5, 6, ., 1, 2, 3, 4]
+        rb.push_back(5);
+        rb.push_back(6);
+        rb.push_front(4);
+        rb.push_front(3);
+        rb.push_front(2);
+        rb.push_front(1);
+
+        let drained: Vec<i32> = rb.drain(1..4).collect();
+        assert_eq!(drained, [2, 3, 4]);
+        assert_eq!(rb.len(), 3);
+        assert_eq!(rb.iter().copied().collect::<Vec<_>>(), [1, 5, 6]);
+    }
+
+    #[test]
+    fn test_drain_partial_iteration_still_closes_gap() {
+        let mut rb = RingBuffer::<i32>::with_capacity(6);
+        for i in 1..=6 {
+            rb.push_back(i);
+        }
+
+        // Only take the first drained element; the rest must still be dropped and the gap
+        // closed when `Drain` itself is dropped.
+        {
+            let mut drain = rb.drain(1..4);
+            assert_eq!(drain.next(), Some(2));
+        }
+
+        assert_eq!(rb.len(), 3);
+        assert_eq!(rb.iter().copied().collect::<Vec<_>>(), [1, 5, 6]);
+    }
+
+    #[test]
+    fn test_drain_full_range() {
+        let mut rb = RingBuffer::<i32>::with_capacity(4);
+        rb.push_back(1);
+        rb.push_back(2);
+        rb.push_back(3);
+
+        let drained: Vec<i32> = rb.drain(..).collect();
+        assert_eq!(drained, [1, 2, 3]);
+        assert!(rb.is_empty());
+    }
 }
@@ -4,16 +4,32 @@
 //! <https://stackoverflow.com/questions/49072494/how-does-the-vecdeque-ring-buffer-work-internally>
 //! <https://doc.rust-lang.org/nomicon/vec/vec-push-pop.html>
 //!
+//! Enable the `no_std` feature to build without the standard library. `RingBuffer` still needs
+//! an allocator (pulled in via the `alloc` crate in that mode); [`ArrayRingBuffer`] needs none.
 #![allow(dead_code)]
-use std::{
+#![cfg_attr(feature = "no_std", no_std)]
+
+#[cfg(feature = "no_std")]
+extern crate alloc as alloc_crate;
+
+// `Layout`/`NonNull`/`from_raw_parts(_mut)` live in `core` either way; only the allocator
+// functions themselves move between `std::alloc` and the `alloc` crate.
+use core::{
     alloc::Layout,
     ptr::NonNull,
     slice::{from_raw_parts, from_raw_parts_mut},
 };
+#[cfg(feature = "no_std")]
+use alloc_crate::alloc::{alloc, dealloc, handle_alloc_error, realloc};
+#[cfg(not(feature = "no_std"))]
+use std::alloc::{alloc, dealloc, handle_alloc_error, realloc};
 
+mod array;
+mod index_math;
 mod iterator;
 mod traits;
-use iterator::Iter;
+pub use array::ArrayRingBuffer;
+use iterator::{Drain, Iter, IterMut};
 
 /// A growable ring buffer.
 /// Contains a pointer to the buffer, the allocated capacity, the current length of the buffer and
@@ -24,31 +40,33 @@ pub struct RingBuffer<T> {
     capacity: usize,
     head: usize,
     len: usize,
+    max_capacity: Option<usize>,
 }
 
 impl<T> RingBuffer<T> {
     pub fn new() -> Self {
-        assert!(std::mem::size_of::<T>() != 0, "ZSTs are not supported.");
+        assert!(core::mem::size_of::<T>() != 0, "ZSTs are not supported.");
         RingBuffer {
             ptr: NonNull::dangling(),
             capacity: 0,
             head: 0,
             len: 0,
+            max_capacity: None,
         }
     }
 
     pub fn with_capacity(capacity: usize) -> Self {
-        assert!(std::mem::size_of::<T>() != 0, "ZSTs are not supported.");
+        assert!(core::mem::size_of::<T>() != 0, "ZSTs are not supported.");
         if capacity == 0 {
             return Self::new();
         }
 
         let layout = Layout::array::<T>(capacity).unwrap();
-        let ptr = unsafe { std::alloc::alloc(layout) };
+        let ptr = unsafe { alloc(layout) };
 
         let ptr = match NonNull::new(ptr as *mut T) {
             Some(ptr) => ptr,
-            None => std::alloc::handle_alloc_error(layout),
+            None => handle_alloc_error(layout),
         };
 
         RingBuffer {
@@ -56,9 +74,22 @@ impl<T> RingBuffer<T> {
             capacity,
             head: 0,
             len: 0,
+            max_capacity: None,
         }
     }
 
+    /// Create a fixed-capacity buffer that never grows.
+    ///
+    /// Once `len` reaches `capacity`, `push_back` evicts and drops the current front element
+    /// (and `push_front` evicts the current back element) instead of reallocating. Use
+    /// [`RingBuffer::push_back_overwrite`] / [`RingBuffer::push_front_overwrite`] to recover the
+    /// evicted value, e.g. for a "last N samples" telemetry window.
+    pub fn bounded(capacity: usize) -> Self {
+        let mut rb = Self::with_capacity(capacity);
+        rb.max_capacity = Some(capacity);
+        rb
+    }
+
     /// Return true if the buffer is empty.
     pub fn is_empty(&self) -> bool {
         self.len == 0
@@ -76,20 +107,134 @@ impl<T> RingBuffer<T> {
             return None;
         }
 
-        let idx = (self.head + index) % self.capacity;
+        let idx = index_math::physical(self.head, self.capacity, index);
         unsafe { Some(&*self.ptr.as_ptr().add(idx)) }
     }
 
-    /**
+    /*
+     * Capacity management.
+     */
+
+    /// Reserve capacity for at least `additional` more elements, doubling past what's strictly
+    /// needed (like `grow`) so a run of pushes after a `reserve` doesn't immediately trigger
+    /// another reallocation.
+    ///
+    /// No-op on a [`bounded`](RingBuffer::bounded) buffer: its capacity is fixed and pushes past
+    /// it evict instead of reallocating.
+    pub fn reserve(&mut self, additional: usize) {
+        if self.max_capacity.is_some() {
+            return;
+        }
+        let required = self.len + additional;
+        if required > self.capacity {
+            self.grow_to(required.max(self.capacity * 2));
+        }
+    }
+
+    /// Reserve capacity for exactly `additional` more elements, without `reserve`'s extra
+    /// doubling headroom.
+    ///
+    /// No-op on a [`bounded`](RingBuffer::bounded) buffer: its capacity is fixed and pushes past
+    /// it evict instead of reallocating.
+    pub fn reserve_exact(&mut self, additional: usize) {
+        if self.max_capacity.is_some() {
+            return;
+        }
+        let required = self.len + additional;
+        if required > self.capacity {
+            self.grow_to(required);
+        }
+    }
+
+    /// Reallocate down to exactly `len()` elements, reclaiming any spare capacity.
+    pub fn shrink_to_fit(&mut self) {
+        if self.capacity == self.len {
+            return;
+        }
+
+        // Make the data contiguous so the smaller allocation can hold it starting at offset 0.
+        self.make_contiguous();
+
+        if self.len == 0 {
+            if self.capacity != 0 {
+                let layout = Layout::array::<T>(self.capacity).unwrap();
+                unsafe {
+                    dealloc(self.ptr.as_ptr() as *mut u8, layout);
+                }
+            }
+            self.ptr = NonNull::dangling();
+            self.capacity = 0;
+            self.head = 0;
+            return;
+        }
+
+        let new_layout = Layout::array::<T>(self.len).unwrap();
+        let new_ptr = unsafe { alloc(new_layout) };
+        let new_ptr = match NonNull::new(new_ptr as *mut T) {
+            Some(ptr) => ptr,
+            None => handle_alloc_error(new_layout),
+        };
+
+        unsafe {
+            self.ptr
+                .as_ptr()
+                .add(self.head)
+                .copy_to_nonoverlapping(new_ptr.as_ptr(), self.len);
+        }
+
+        let old_layout = Layout::array::<T>(self.capacity).unwrap();
+        unsafe {
+            dealloc(self.ptr.as_ptr() as *mut u8, old_layout);
+        }
+
+        self.ptr = new_ptr;
+        self.capacity = self.len;
+        self.head = 0;
+    }
+
+    /// Drop elements from the back until at most `len` remain. Does nothing if the buffer is
+    /// already shorter than `len`.
+    pub fn truncate(&mut self, len: usize) {
+        while self.len > len {
+            self.pop_back();
+        }
+    }
+
+    /*
      * Inserting elements.
      */
 
     /// Insert an item at the front of the buffer.
     pub fn push_front(&mut self, item: T) {
-        if self.is_full() {
-            self.grow();
+        self.push_front_impl(item);
+    }
+
+    /// Insert an item at the front of the buffer, evicting and returning the back element if the
+    /// buffer is [`bounded`](RingBuffer::bounded) and full.
+    ///
+    /// Returns `None` if nothing was evicted (the buffer is unbounded, or had spare capacity).
+    pub fn push_front_overwrite(&mut self, item: T) -> Option<T> {
+        self.push_front_impl(item)
+    }
+
+    fn push_front_impl(&mut self, item: T) -> Option<T> {
+        if self.max_capacity == Some(0) {
+            // Zero-capacity bounded buffer: there's no slot to write into, so the pushed item is
+            // evicted immediately instead of being stored.
+            return Some(item);
         }
 
+        let evicted = if self.is_full() {
+            if self.max_capacity.is_some() {
+                self.pop_back()
+            } else {
+                self.grow();
+                None
+            }
+        } else {
+            None
+        };
+
         let index = (self.head + self.capacity - 1) % self.capacity;
 
         unsafe {
@@ -98,23 +243,50 @@ impl<T> RingBuffer<T> {
 
         self.head = index;
         self.len += 1;
+        evicted
     }
 
     /// Insert an item at the end of the buffer.
     pub fn push_back(&mut self, item: T) {
-        if self.is_full() {
-            self.grow();
+        self.push_back_impl(item);
+    }
+
+    /// Insert an item at the end of the buffer, evicting and returning the front element if the
+    /// buffer is [`bounded`](RingBuffer::bounded) and full.
+    ///
+    /// Returns `None` if nothing was evicted (the buffer is unbounded, or had spare capacity).
+    pub fn push_back_overwrite(&mut self, item: T) -> Option<T> {
+        self.push_back_impl(item)
+    }
+
+    fn push_back_impl(&mut self, item: T) -> Option<T> {
+        if self.max_capacity == Some(0) {
+            // Zero-capacity bounded buffer: there's no slot to write into, so the pushed item is
+            // evicted immediately instead of being stored.
+            return Some(item);
         }
 
+        let evicted = if self.is_full() {
+            if self.max_capacity.is_some() {
+                self.pop_front()
+            } else {
+                self.grow();
+                None
+            }
+        } else {
+            None
+        };
+
         let index = (self.head + self.len) % self.capacity;
 
         unsafe {
             self.ptr.as_ptr().add(index).write(item);
         }
         self.len += 1;
+        evicted
     }
 
-    /**
+    /*
      * Removing elements.
      */
 
@@ -144,13 +316,13 @@ impl<T> RingBuffer<T> {
         Some(item)
     }
 
-    /**
+    /*
      * Memory layout.
      */
 
     /// Return true if the buffer is contiguous in memory.
     pub fn is_contiguous(&self) -> bool {
-        self.head + self.len <= self.capacity
+        index_math::is_contiguous(self.head, self.len, self.capacity)
     }
 
     /// Return the buffer a pair of slices.
@@ -159,29 +331,15 @@ impl<T> RingBuffer<T> {
     /// The front of the buffer is always the first slice and the back is always the second.
     /// If the buffer is contiguous then the second slice will be empty.
     pub fn as_slices(&self) -> (&[T], &[T]) {
-        if self.is_contiguous() {
-            let slice = unsafe { from_raw_parts(self.ptr.as_ptr().add(self.head), self.len) };
-            return (slice, &[]);
-        }
-
-        let top = self.capacity - self.head;
-        let bottom = self.len - top;
-
+        let (top, bottom) = index_math::segment_lens(self.head, self.len, self.capacity);
         let first = unsafe { from_raw_parts(self.ptr.as_ptr().add(self.head), top) };
         let second = unsafe { from_raw_parts(self.ptr.as_ptr(), bottom) };
         (first, second)
     }
 
     /// Return the buffer as a pair of mutable slices.
-    pub fn as_mut_slices(&self) -> (&mut [T], &mut [T]) {
-        if self.is_contiguous() {
-            let slice = unsafe { from_raw_parts_mut(self.ptr.as_ptr().add(self.head), self.len) };
-            return (slice, &mut []);
-        }
-
-        let top = self.capacity - self.head;
-        let bottom = self.len - top;
-
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let (top, bottom) = index_math::segment_lens(self.head, self.len, self.capacity);
         let first = unsafe { from_raw_parts_mut(self.ptr.as_ptr().add(self.head), top) };
         let second = unsafe { from_raw_parts_mut(self.ptr.as_ptr(), bottom) };
         (first, second)
@@ -254,14 +412,73 @@ impl<T> RingBuffer<T> {
         slice
     }
 
+    /*
+     * Bulk transfer.
+     */
+
+    /// Grow to make room for (up to) `n` more elements if unbounded, then call `f` with the
+    /// writable free region at the back of the buffer as a pair of (possibly uninitialized)
+    /// slices (in the same front/back wrap order as
+    /// [`as_mut_slices`](RingBuffer::as_mut_slices)), and commit `n` elements to `len`.
+    ///
+    /// `f` must initialize the first `n` elements across the two slices before returning; this
+    /// lets a caller fill the buffer directly, e.g. from a socket `recv`, without an intermediate
+    /// buffer. The slices are exposed as `MaybeUninit<T>` because the backing storage hasn't been
+    /// initialized yet; writing through them with anything other than `MaybeUninit::write` (or
+    /// equivalent) is undefined behavior. If the buffer is [`bounded`](RingBuffer::bounded) and
+    /// has fewer than `n` free slots, only the available elements are committed.
+    pub fn enqueue_many(
+        &mut self,
+        n: usize,
+        f: impl FnOnce(&mut [core::mem::MaybeUninit<T>], &mut [core::mem::MaybeUninit<T>]),
+    ) {
+        if self.max_capacity.is_none() {
+            self.reserve_exact(n);
+        }
+
+        if self.capacity == 0 {
+            f(&mut [], &mut []);
+            return;
+        }
+
+        let free = self.capacity - self.len;
+        let tail = (self.head + self.len) % self.capacity;
+        let first_len = (self.capacity - tail).min(free);
+        let second_len = free - first_len;
+
+        unsafe {
+            let base = self.ptr.as_ptr() as *mut core::mem::MaybeUninit<T>;
+            let first = from_raw_parts_mut(base.add(tail), first_len);
+            let second = from_raw_parts_mut(base, second_len);
+            f(first, second);
+        }
+
+        self.len += n.min(free);
+    }
+
     /*
      * Iteration.
      */
 
-    pub fn iter(&self) -> Iter<T> {
+    pub fn iter(&self) -> Iter<'_, T> {
         Iter { rb: self, index: 0 }
     }
 
+    /// Return an iterator over mutably borrowed elements, in logical front-to-back order.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let (first, second) = self.as_mut_slices();
+        IterMut::new(first, second)
+    }
+
+    /// Remove the elements in the logical range `range`, returning them as a draining iterator.
+    ///
+    /// If the `Drain` is dropped before being fully iterated, the remaining elements in the
+    /// range are dropped too and the gap is still closed, leaving the buffer at `len() -
+    /// range.len()`. See [`Drain`] for what happens if it's leaked instead of dropped.
+    pub fn drain<R: core::ops::RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T> {
+        Drain::new(self, range)
+    }
+
     /*
      * Private methods.
      */
@@ -278,7 +495,12 @@ impl<T> RingBuffer<T> {
         } else {
             self.capacity * 2
         };
+        self.grow_to(new_cap);
+    }
 
+    /// Reallocate to exactly `new_cap` elements (which must be `>= self.capacity`), relocating
+    /// the wrapped segment so existing elements stay logically intact.
+    fn grow_to(&mut self, new_cap: usize) {
         // Safe unwrap because we know that `new_cap` is <= usize::MAX
         let new_layout = Layout::array::<T>(new_cap).unwrap();
 
@@ -289,58 +511,107 @@ impl<T> RingBuffer<T> {
 
         let new_ptr = if self.capacity == 0 {
             // Allocate a new buffer
-            unsafe { std::alloc::alloc(new_layout) }
+            unsafe { alloc(new_layout) }
         } else {
             let old_layout = Layout::array::<T>(self.capacity).unwrap();
             let old_ptr = self.ptr.as_ptr() as *mut u8;
-            unsafe { std::alloc::realloc(old_ptr, old_layout, new_layout.size()) }
+            unsafe { realloc(old_ptr, old_layout, new_layout.size()) }
         };
 
         // Abort if the allocation fails
         self.ptr = match NonNull::new(new_ptr as *mut T) {
             Some(ptr) => ptr,
-            None => std::alloc::handle_alloc_error(new_layout),
+            None => handle_alloc_error(new_layout),
         };
 
-        // If the buffer wrapped around the end of the allocated memory, we need to update it now
-        // that we have re-allocated.
+        // If the buffer wrapped around the end of the allocated memory, the physical tail
+        // `[head, capacity)` needs to move to the end of the new, larger allocation, so the gap
+        // opened up by growing appears where the old allocation used to wrap around (the end)
+        // instead of in the middle of the existing elements. The segment that was already
+        // wrapped around to the start, `[0, len - (capacity - head))`, needs no adjustment: those
+        // bytes are untouched by `realloc` and still sit at the same offset.
+        //
+        //        H                 C                             H                           C'
+        // [o, o, o, o, o, o, o, o]           ->  [o, o, ., ., ., ., ., ., ., ., ., o, o, o, o, o]
         if self.head != 0 {
-            if (self.capacity - self.head) >= self.head {
-                // If the portion from the start of the buffer is smaller than the rest then we
-                // move it to the end.
-                //
-                //        H                 C
-                // [o, o, o, o, o, o, o, o]
-                //
-                //           H                        H+L
-                // -> [., ., o, o, o, o, o, o, o, o., ., ., ., ., ., .]
-                unsafe {
-                    self.ptr
-                        .as_ptr()
-                        .copy_to(self.ptr.as_ptr().add(self.capacity), self.head);
-                }
-            } else {
-                // If the portion from the start of the buffer is smaller than the rest then we
-                // move it to the end.
-                //
-                //                    H     C
-                // [o, o, o, o, o, o, o, o]
-                //
-                //                      H+L                      H    C
-                // -> [o, o, o, o, o, o, ., ., ., ., ., ., ., ., o, o]
-                unsafe {
-                    self.ptr.as_ptr().add(self.head).copy_to(
-                        self.ptr.as_ptr().add(new_cap - self.head),
-                        self.capacity - self.head,
-                    );
-                }
-                self.head = new_cap - self.head;
+            let tail_len = self.capacity - self.head;
+            let new_head = new_cap - tail_len;
+            unsafe {
+                self.ptr
+                    .as_ptr()
+                    .add(self.head)
+                    .copy_to(self.ptr.as_ptr().add(new_head), tail_len);
             }
+            self.head = new_head;
         }
         self.capacity = new_cap;
     }
 }
 
+/// Bulk byte/packet-buffer style transfer for `Copy` element types.
+impl<T: Copy> RingBuffer<T> {
+    /// Copy as many elements of `data` as fit into the free region at the back of the buffer,
+    /// growing first if the buffer is unbounded. Returns the number of elements copied, which is
+    /// `data.len()` unless the buffer is [`bounded`](RingBuffer::bounded) and fills up first.
+    ///
+    /// This writes directly into the (possibly wrapped) free region with `copy_nonoverlapping`
+    /// instead of calling `push_back` once per element, which is significantly faster for large
+    /// transfers such as filling the buffer from a socket read.
+    pub fn enqueue_slice(&mut self, data: &[T]) -> usize {
+        if self.max_capacity.is_none() {
+            self.reserve_exact(data.len());
+        }
+
+        let free = self.capacity - self.len;
+        let n = data.len().min(free);
+        if n == 0 {
+            return 0;
+        }
+
+        let tail = (self.head + self.len) % self.capacity;
+        let first_len = (self.capacity - tail).min(n);
+
+        unsafe {
+            core::ptr::copy_nonoverlapping(data.as_ptr(), self.ptr.as_ptr().add(tail), first_len);
+            if n > first_len {
+                core::ptr::copy_nonoverlapping(
+                    data.as_ptr().add(first_len),
+                    self.ptr.as_ptr(),
+                    n - first_len,
+                );
+            }
+        }
+        self.len += n;
+        n
+    }
+
+    /// Copy out of the front of the buffer into `out`, advancing `head` past the copied
+    /// elements. Returns the number of elements copied, which is `out.len()` unless the buffer
+    /// holds fewer elements than that.
+    pub fn dequeue_slice(&mut self, out: &mut [T]) -> usize {
+        let n = out.len().min(self.len);
+        if n == 0 {
+            return 0;
+        }
+
+        let first_len = (self.capacity - self.head).min(n);
+
+        unsafe {
+            core::ptr::copy_nonoverlapping(self.ptr.as_ptr().add(self.head), out.as_mut_ptr(), first_len);
+            if n > first_len {
+                core::ptr::copy_nonoverlapping(
+                    self.ptr.as_ptr(),
+                    out.as_mut_ptr().add(first_len),
+                    n - first_len,
+                );
+            }
+        }
+        self.head = (self.head + n) % self.capacity;
+        self.len -= n;
+        n
+    }
+}
+
 impl<T> Drop for RingBuffer<T> {
     fn drop(&mut self) {
         if self.capacity != 0 {
@@ -349,7 +620,7 @@ impl<T> Drop for RingBuffer<T> {
             // Deallocate the buffer.
             let layout = Layout::array::<T>(self.capacity).unwrap();
             unsafe {
-                std::alloc::dealloc(self.ptr.as_ptr() as *mut u8, layout);
+                dealloc(self.ptr.as_ptr() as *mut u8, layout);
             }
         }
     }
@@ -359,24 +630,8 @@ impl<T> Drop for RingBuffer<T> {
 mod tests {
 
     use super::*;
-
-    macro_rules! buffer_from_layout {
-        ($len:tt: [$( $n:tt ),* ... $( $m:tt ),*]) => {{
-            let mut rb = RingBuffer::<i32>::with_capacity($len);
-
-            $(
-                rb.push_back($n);
-            )*
-
-            let mut front = vec![$($m),*];
-            front.reverse();
-            for i in front {
-                rb.push_front(i);
-            }
-
-            rb
-        }};
-    }
+    #[cfg(feature = "no_std")]
+    use alloc_crate::vec::Vec;
 
     #[test]
     fn test_new() {
@@ -447,11 +702,12 @@ mod tests {
         assert_eq!(rb[0], 2);
         assert_eq!(rb[1], 1);
 
-        //     H         H               H  (see `grow` for why this happens)
-        // [1, 2] -> [1, 2, ., .] -> [., 2, 1, .] -> [3, 2, 1, .]
+        // Growing relocates the physical tail `[head, capacity)` to the end of the new
+        // allocation (see `grow_to`), so `head` moves from 1 to 2 here; the logical order is
+        // unaffected.
         rb.push_front(3);
         assert_eq!(rb.len, 3);
-        assert_eq!(rb.head, 0);
+        assert_eq!(rb.head, 2);
         assert_eq!(rb[0], 3);
         assert_eq!(rb[1], 2);
         assert_eq!(rb[2], 1);
@@ -564,6 +820,235 @@ mod tests {
         assert_eq!(slice, [1, 2, 3, 4, 5, 6]);
     }
 
+    #[test]
+    fn test_bounded_push_back_overwrite() {
+        let mut rb = RingBuffer::<i32>::bounded(3);
+
+        assert_eq!(rb.push_back_overwrite(1), None);
+        assert_eq!(rb.push_back_overwrite(2), None);
+        assert_eq!(rb.push_back_overwrite(3), None);
+        assert_eq!(rb.capacity, 3);
+
+        // Buffer is full: pushing evicts the front.
+        assert_eq!(rb.push_back_overwrite(4), Some(1));
+        assert_eq!(rb.capacity, 3);
+        assert_eq!(rb.iter().copied().collect::<Vec<_>>(), [2, 3, 4]);
+
+        rb.push_back(5);
+        assert_eq!(rb.iter().copied().collect::<Vec<_>>(), [3, 4, 5]);
+    }
+
+    #[test]
+    fn test_bounded_push_front_overwrite() {
+        let mut rb = RingBuffer::<i32>::bounded(3);
+
+        assert_eq!(rb.push_front_overwrite(1), None);
+        assert_eq!(rb.push_front_overwrite(2), None);
+        assert_eq!(rb.push_front_overwrite(3), None);
+        assert_eq!(rb.capacity, 3);
+
+        // Buffer is full: pushing evicts the back.
+        assert_eq!(rb.push_front_overwrite(4), Some(1));
+        assert_eq!(rb.capacity, 3);
+        assert_eq!(rb.iter().copied().collect::<Vec<_>>(), [4, 3, 2]);
+
+        rb.push_front(5);
+        assert_eq!(rb.iter().copied().collect::<Vec<_>>(), [5, 4, 3]);
+    }
+
+    #[test]
+    fn test_bounded_zero_capacity_evicts_immediately() {
+        let mut rb = RingBuffer::<i32>::bounded(0);
+        assert_eq!(rb.push_back_overwrite(1), Some(1));
+        assert_eq!(rb.push_front_overwrite(2), Some(2));
+        assert!(rb.is_empty());
+        assert_eq!(rb.capacity, 0);
+    }
+
+    #[test]
+    fn test_enqueue_dequeue_slice() {
+        let mut rb = RingBuffer::<u8>::with_capacity(4);
+
+        // Wrap the write region: [2, 3, ., 1]
+        rb.push_back(1);
+        let mut out = [0u8; 1];
+        assert_eq!(rb.dequeue_slice(&mut out), 1);
+        assert_eq!(out, [1]);
+
+        assert_eq!(rb.enqueue_slice(&[2, 3, 4, 5]), 4);
+        assert_eq!(rb.iter().copied().collect::<Vec<_>>(), [2, 3, 4, 5]);
+
+        let mut out = [0u8; 2];
+        assert_eq!(rb.dequeue_slice(&mut out), 2);
+        assert_eq!(out, [2, 3]);
+        assert_eq!(rb.iter().copied().collect::<Vec<_>>(), [4, 5]);
+
+        let mut out = [0u8; 10];
+        assert_eq!(rb.dequeue_slice(&mut out), 2);
+        assert_eq!(&out[..2], [4, 5]);
+        assert_eq!(rb.dequeue_slice(&mut out), 0);
+    }
+
+    #[test]
+    fn test_enqueue_slice_bounded_truncates() {
+        let mut rb = RingBuffer::<u8>::bounded(3);
+        assert_eq!(rb.enqueue_slice(&[1, 2, 3, 4]), 3);
+        assert_eq!(rb.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_enqueue_slice_grows_wrapped_buffer() {
+        let mut rb = RingBuffer::<u8>::with_capacity(8);
+
+        // [10, 20, ., ., 2, 3, 4, 5] wraps around the end of an 8-capacity allocation.
+        rb.push_back(10);
+        rb.push_back(20);
+        rb.push_front(5);
+        rb.push_front(4);
+        rb.push_front(3);
+        rb.push_front(2);
+
+        // Growing to fit must relocate the wrapped segment correctly instead of corrupting it.
+        assert_eq!(rb.enqueue_slice(&[100, 101, 102]), 3);
+        assert_eq!(
+            rb.iter().copied().collect::<Vec<_>>(),
+            [2, 3, 4, 5, 10, 20, 100, 101, 102]
+        );
+    }
+
+    #[test]
+    fn test_enqueue_many() {
+        let mut rb = RingBuffer::<u8>::with_capacity(2);
+        rb.push_back(9);
+
+        // Only one free slot to start; `enqueue_many` must grow to fit all 3.
+        rb.enqueue_many(3, |first, second| {
+            let written = [1u8, 2, 3];
+            let (written_first, written_second) = written.split_at(first.len());
+            for (slot, value) in first.iter_mut().zip(written_first) {
+                slot.write(*value);
+            }
+            for (slot, value) in second.iter_mut().zip(written_second) {
+                slot.write(*value);
+            }
+        });
+
+        assert_eq!(rb.iter().copied().collect::<Vec<_>>(), [9, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_reserve() {
+        let mut rb = RingBuffer::<i32>::with_capacity(2);
+        rb.push_back(1);
+
+        rb.reserve(5);
+        assert!(rb.capacity >= 6);
+        assert_eq!(rb.iter().copied().collect::<Vec<_>>(), [1]);
+    }
+
+    #[test]
+    fn test_reserve_exact() {
+        let mut rb = RingBuffer::<i32>::with_capacity(2);
+        rb.push_back(1);
+
+        rb.reserve_exact(5);
+        assert_eq!(rb.capacity, 6);
+        assert_eq!(rb.iter().copied().collect::<Vec<_>>(), [1]);
+
+        // Already enough spare capacity: no-op.
+        rb.reserve_exact(1);
+        assert_eq!(rb.capacity, 6);
+    }
+
+    #[test]
+    fn test_reserve_bounded_is_noop() {
+        let mut rb = RingBuffer::<i32>::bounded(3);
+        rb.push_back(1);
+        rb.push_back(2);
+        rb.push_back(3);
+
+        rb.reserve(10);
+        rb.reserve_exact(10);
+        assert_eq!(rb.capacity, 3);
+
+        // Still evicts on overflow instead of having grown past capacity.
+        rb.push_back(4);
+        assert_eq!(rb.iter().copied().collect::<Vec<_>>(), [2, 3, 4]);
+    }
+
+    #[test]
+    fn test_reserve_relocates_wrapped_segment() {
+        let mut rb = RingBuffer::<i32>::with_capacity(4);
+
+        // [3, 4, ., 1, 2]... wraps around the end of a 4-capacity allocation.
+        rb.push_back(3);
+        rb.push_back(4);
+        rb.push_front(2);
+        rb.push_front(1);
+
+        rb.reserve_exact(4);
+        assert_eq!(rb.iter().copied().collect::<Vec<_>>(), [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_reserve_relocates_wrapped_segment_partial_non_doubling() {
+        // `len < capacity` and the required capacity isn't `2 * capacity`, unlike
+        // `test_reserve_relocates_wrapped_segment` above: this exercises `grow_to` with a
+        // `new_cap` that doesn't happen to match the one geometry a naive implementation might
+        // special-case for.
+        let mut rb = RingBuffer::<i32>::with_capacity(8);
+
+        // [10, 20, ., ., 2, 3, 4, 5] wraps around the end of an 8-capacity allocation, with two
+        // free slots left over.
+        rb.push_back(10);
+        rb.push_back(20);
+        rb.push_front(5);
+        rb.push_front(4);
+        rb.push_front(3);
+        rb.push_front(2);
+
+        rb.reserve_exact(3);
+        assert_eq!(
+            rb.iter().copied().collect::<Vec<_>>(),
+            [2, 3, 4, 5, 10, 20]
+        );
+    }
+
+    #[test]
+    fn test_shrink_to_fit() {
+        let mut rb = RingBuffer::<i32>::with_capacity(10);
+
+        // [3, 4, ., ., ., ., ., ., 1, 2] wraps around the end.
+        rb.push_back(3);
+        rb.push_back(4);
+        rb.push_front(2);
+        rb.push_front(1);
+
+        rb.shrink_to_fit();
+        assert_eq!(rb.capacity, 4);
+        assert_eq!(rb.head, 0);
+        assert_eq!(rb.iter().copied().collect::<Vec<_>>(), [1, 2, 3, 4]);
+
+        rb.truncate(0);
+        rb.shrink_to_fit();
+        assert_eq!(rb.capacity, 0);
+    }
+
+    #[test]
+    fn test_truncate() {
+        let mut rb = RingBuffer::<i32>::with_capacity(5);
+        for i in 1..=5 {
+            rb.push_back(i);
+        }
+
+        rb.truncate(3);
+        assert_eq!(rb.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+
+        // Truncating to a larger length than the buffer holds is a no-op.
+        rb.truncate(10);
+        assert_eq!(rb.len(), 3);
+    }
+
     #[test]
     fn test_as_slices() {
         let mut rb = RingBuffer::<i32>::with_capacity(5);
@@ -1,6 +1,6 @@
 // Implementations of some common traits for the RingBuffer.
 use crate::RingBuffer;
-use std::{
+use core::{
     cmp::{Eq, PartialEq},
     fmt::Display,
     ops::Index,
@@ -18,6 +18,40 @@ impl<T> Default for RingBuffer<T> {
     }
 }
 
+impl<T: Clone> Clone for RingBuffer<T> {
+    fn clone(&self) -> Self {
+        let mut rb = RingBuffer::with_capacity(self.capacity);
+        rb.max_capacity = self.max_capacity;
+        for item in self.iter() {
+            rb.push_back(item.clone());
+        }
+        rb
+    }
+}
+
+impl<T> FromIterator<T> for RingBuffer<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut rb = RingBuffer::new();
+        rb.extend(iter);
+        rb
+    }
+}
+
+/// Pushes elements onto the back, pre-reserving capacity from `size_hint` to avoid repeated
+/// `grow()` reallocations.
+impl<T> Extend<T> for RingBuffer<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        if self.max_capacity.is_none() {
+            let (lower, _) = iter.size_hint();
+            self.reserve_exact(lower);
+        }
+        for item in iter {
+            self.push_back(item);
+        }
+    }
+}
+
 /// Allow raw indexing into the buffer.
 ///
 /// Warning: this will panic if the index is out of bounds.
@@ -30,7 +64,7 @@ impl<T> Index<usize> for RingBuffer<T> {
 }
 
 impl<T: Display> Display for RingBuffer<T> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.write_str("[")?;
         for (i, item) in self.iter().enumerate() {
             if i > 0 {
@@ -49,3 +83,32 @@ impl<T: PartialEq> PartialEq for RingBuffer<T> {
 }
 
 impl<T: Eq> Eq for RingBuffer<T> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::RingBuffer;
+    #[cfg(feature = "no_std")]
+    use crate::alloc_crate::vec::Vec;
+
+    #[test]
+    fn test_extend_wrapped_buffer() {
+        let mut rb = RingBuffer::<i32>::with_capacity(8);
+
+        // [10, 20, ., ., 2, 3, 4, 5] wraps around the end of an 8-capacity allocation.
+        rb.push_back(10);
+        rb.push_back(20);
+        rb.push_front(5);
+        rb.push_front(4);
+        rb.push_front(3);
+        rb.push_front(2);
+
+        // `Extend::extend` reserves via `reserve_exact`, which grows a wrapped, non-full buffer
+        // to a non-doubled capacity; this must relocate the wrapped segment correctly rather than
+        // corrupting it.
+        rb.extend([100, 200, 300]);
+        assert_eq!(
+            rb.iter().copied().collect::<Vec<_>>(),
+            [2, 3, 4, 5, 10, 20, 100, 200, 300]
+        );
+    }
+}